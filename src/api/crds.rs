@@ -1,5 +1,5 @@
 use crate::{
-    api::{raw::RawApi, typed::Api},
+    api::{discovery::ApiResource, dynamic::DynamicObject, raw::RawApi, typed::Api},
     client::APIClient,
 };
 use inflector::{cases::pascalcase::is_pascal_case, string::pluralize::to_plural};
@@ -15,6 +15,8 @@ pub struct CustomResource {
     version: String,
     api_version: String,
     namespace: Option<String>,
+    plural: Option<String>,
+    with_status: bool,
 }
 
 impl CustomResource {
@@ -22,6 +24,24 @@ impl CustomResource {
     pub fn new(kind: &str) -> CrBuilder {
         CrBuilder::new(kind)
     }
+
+    /// Construct a `CrBuilder` pre-filled with a discovered `ApiResource`
+    ///
+    /// Uses discovery's authoritative `kind`, `group`, `version` and plural
+    /// directly, skipping the `to_plural(kind)` guess `CrBuilder::new` relies
+    /// on (and the heuristic assert that goes with it, which exists only to
+    /// catch a hand-written plural `kind` and has no business examining
+    /// server-reported data). Still returns a builder rather than a built
+    /// `CustomResource` so callers can call `.within(ns)` for namespaced
+    /// resources before `.build()`.
+    pub fn from_api_resource(resource: &ApiResource) -> CrBuilder {
+        CrBuilder::from_discovered(&resource.kind, &resource.group, &resource.version, &resource.name)
+    }
+
+    /// Whether this CR was built with `.with_status()`, i.e. exposes a `/status` subresource
+    pub fn has_status(&self) -> bool {
+        self.with_status
+    }
 }
 
 /// A builder for CustomResource
@@ -31,6 +51,8 @@ pub struct CrBuilder {
     pub(crate) version: Option<String>,
     pub(crate) group: Option<String>,
     pub(crate) namespace: Option<String>,
+    pub(crate) plural: Option<String>,
+    pub(crate) with_status: bool,
 }
 impl CrBuilder {
     /// Create a CrBuilder
@@ -56,6 +78,21 @@ impl CrBuilder {
         }
     }
 
+    /// Create a CrBuilder from authoritative discovery data
+    ///
+    /// Unlike `new`, this takes `group`/`version`/`plural` up front and skips
+    /// `new`'s heuristic asserts, since the values come straight from the
+    /// apiserver rather than a hand-written guess.
+    fn from_discovered(kind: &str, group: &str, version: &str, plural: &str) -> Self {
+        Self {
+            kind: kind.into(),
+            group: Some(group.to_string()),
+            version: Some(version.to_string()),
+            plural: Some(plural.to_string()),
+            ..Default::default()
+        }
+    }
+
     /// Set the api group of a custom resource
     pub fn group(mut self, group: &str) -> Self {
         self.group = Some(group.to_string());
@@ -74,16 +111,40 @@ impl CrBuilder {
         self
     }
 
+    /// Set an explicit plural for a custom resource
+    ///
+    /// Overrides the `to_plural(kind)` guess used in request URIs. Needed
+    /// whenever the CRD's `spec.names.plural` differs from the naive
+    /// pluralization of `kind`.
+    pub fn plural(mut self, plural: &str) -> Self {
+        self.plural = Some(plural.to_string());
+        self
+    }
+
+    /// Mark the CRD as exposing a `/status` subresource
+    pub fn with_status(mut self) -> Self {
+        self.with_status = true;
+        self
+    }
+
     // Build a RawApi from Crd properties
     pub fn build(self) -> CustomResource {
         let version = self.version.expect("Crd must have a version");
-        let group = self.group.expect("Crd must have a group");
+        // group is optional: resources in the core (empty) group have none
+        let group = self.group.unwrap_or_default();
+        let api_version = if group.is_empty() {
+            version.clone()
+        } else {
+            format!("{}/{}", group, version)
+        };
         CustomResource {
-            api_version: format!("{}/{}", group, version),
+            api_version,
             kind: self.kind,
             version,
             group,
             namespace: self.namespace,
+            plural: self.plural,
+            with_status: self.with_status,
         }
     }
 }
@@ -97,6 +158,7 @@ impl<K> From<CustomResource> for RawApi<K> {
             group: c.group,
             version: c.version,
             namespace: c.namespace,
+            resource: c.plural,
             phantom: PhantomData,
         }
     }
@@ -111,12 +173,22 @@ impl CustomResource {
             phantom: PhantomData,
         }
     }
+
+    /// Build an `Api<DynamicObject>` for this resource without a compile-time `K`
+    ///
+    /// Composes with `Discovery`: feed a discovered `ApiResource` into
+    /// `from_api_resource`, optionally `.within(ns)` it, `.build()`, then call
+    /// this to list/get/create/patch the resource and inspect its fields as
+    /// JSON, with no generated struct.
+    pub fn to_dynamic_api(self, client: APIClient) -> Api<DynamicObject> {
+        self.to_api(client)
+    }
 }
 
 
 #[cfg(test)]
 mod test {
-    use crate::api::{CustomResource, PatchParams, PostParams, RawApi};
+    use crate::api::{ApiResource, CustomResource, PatchParams, PostParams, RawApi};
     // non-openapi tests
     #[test]
     fn raw_custom_resource() {
@@ -136,6 +208,94 @@ mod test {
         assert_eq!(req.method(), "PATCH");
     }
 
+    #[test]
+    fn raw_custom_resource_explicit_plural() {
+        struct Foo {};
+        let r: RawApi<Foo> = CustomResource::new("Foo")
+            .group("clux.dev")
+            .version("v1")
+            .within("myns")
+            .plural("foozers")
+            .build()
+            .into();
+        let pp = PostParams::default();
+        let req = r.create(&pp, vec![]).unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foozers?");
+        let patch_params = PatchParams::default();
+        let req = r.patch("baz", &patch_params, vec![]).unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foozers/baz?");
+        assert_eq!(req.method(), "PATCH");
+    }
+
+    #[test]
+    fn raw_custom_resource_from_discovered_api_resource() {
+        struct Foo {};
+        let discovered = ApiResource {
+            group: "clux.dev".to_string(),
+            version: "v1".to_string(),
+            kind: "Foo".to_string(),
+            name: "foozers".to_string(), // discovered plural differs from to_plural("Foo")
+            namespaced: true,
+            verbs: vec!["get".to_string(), "list".to_string()],
+        };
+        let r: RawApi<Foo> = CustomResource::from_api_resource(&discovered)
+            .within("myns")
+            .build()
+            .into();
+        let pp = PostParams::default();
+        let req = r.create(&pp, vec![]).unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foozers?");
+    }
+
+    #[test]
+    fn raw_custom_resource_core_group_namespaced() {
+        struct Foo {};
+        let r: RawApi<Foo> = CustomResource::new("Foo")
+            .version("v1")
+            .within("myns")
+            .build()
+            .into();
+        let pp = PostParams::default();
+        let req = r.create(&pp, vec![]).unwrap();
+        assert_eq!(req.uri(), "/api/v1/namespaces/myns/foos?");
+    }
+
+    #[test]
+    fn raw_custom_resource_core_group_cluster_scoped() {
+        struct Foo {};
+        let r: RawApi<Foo> = CustomResource::new("Foo").version("v1").build().into();
+        let pp = PostParams::default();
+        let req = r.create(&pp, vec![]).unwrap();
+        assert_eq!(req.uri(), "/api/v1/foos?");
+    }
+
+    #[test]
+    fn raw_custom_resource_status_subresource() {
+        struct Foo {};
+        let cr = CustomResource::new("Foo")
+            .group("clux.dev")
+            .version("v1")
+            .within("myns")
+            .with_status()
+            .build();
+        assert!(cr.has_status());
+        let r: RawApi<Foo> = cr.into();
+
+        let req = r.get_status("baz").unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foos/baz/status");
+        assert_eq!(req.method(), "GET");
+
+        let pp = PostParams::default();
+        let req = r.replace_status("baz", &pp, vec![]).unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foos/baz/status?");
+        assert_eq!(req.method(), "PUT");
+
+        let patch_params = PatchParams::default();
+        let req = r.patch_status("baz", &patch_params, vec![]).unwrap();
+        assert_eq!(req.uri(), "/apis/clux.dev/v1/namespaces/myns/foos/baz/status?");
+        assert_eq!(req.method(), "PATCH");
+    }
+
 
     #[cfg(feature = "openapi")]
     #[tokio::test]
@@ -0,0 +1,276 @@
+use crate::{client::APIClient, Error, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A Kubernetes group/version/kind triple, used to key discovered resources
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GroupVersionKind {
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+}
+
+/// Authoritative resource info as reported by the apiserver's discovery endpoints
+///
+/// Unlike a hand built `CustomResource`, `name` here is the real plural taken
+/// from `APIResource::name` rather than a guess via `to_plural`.
+#[derive(Clone, Debug)]
+pub struct ApiResource {
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+    pub name: String,
+    pub namespaced: bool,
+    pub verbs: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct APIGroupList {
+    groups: Vec<APIGroup>,
+}
+
+#[derive(Deserialize)]
+struct APIGroup {
+    name: String,
+    versions: Vec<GroupVersionForDiscovery>,
+    #[serde(rename = "preferredVersion")]
+    preferred_version: Option<GroupVersionForDiscovery>,
+}
+
+#[derive(Deserialize)]
+struct GroupVersionForDiscovery {
+    version: String,
+}
+
+#[derive(Deserialize)]
+struct APIResourceList {
+    resources: Vec<APIResourceInfo>,
+}
+
+#[derive(Deserialize)]
+struct APIResourceInfo {
+    name: String,
+    kind: String,
+    namespaced: bool,
+    #[serde(default)]
+    verbs: Vec<String>,
+}
+
+/// In-memory index of discovered resources
+///
+/// Kept separate from `Discovery` itself so the indexing and resolution logic
+/// - the part with actual behaviour to get wrong - can be unit tested without
+/// a live cluster or a mocked `APIClient`.
+#[derive(Default)]
+struct ResourceIndex {
+    resources: HashMap<GroupVersionKind, ApiResource>,
+    // group name -> the apiserver's preferredVersion for that group
+    preferred_versions: HashMap<String, String>,
+}
+
+impl ResourceIndex {
+    fn set_preferred_version(&mut self, group: &str, version: &str) {
+        self.preferred_versions.insert(group.to_string(), version.to_string());
+    }
+
+    fn index_resource_list(&mut self, group: &str, version: &str, list: APIResourceList) {
+        for r in list.resources {
+            // subresources are reported as e.g. "pods/status" - not resources in their own right
+            if r.name.contains('/') {
+                continue;
+            }
+            let gvk = GroupVersionKind {
+                group: group.to_string(),
+                version: version.to_string(),
+                kind: r.kind.clone(),
+            };
+            let resource = ApiResource {
+                group: group.to_string(),
+                version: version.to_string(),
+                kind: r.kind,
+                name: r.name,
+                namespaced: r.namespaced,
+                verbs: r.verbs,
+            };
+            self.resources.insert(gvk, resource);
+        }
+    }
+
+    /// Resolve a `kind` to its `ApiResource`
+    ///
+    /// When a kind is installed under more than one group/version, this
+    /// prefers the match whose version is that group's `preferredVersion`.
+    /// Ties (or the absence of a preferred version) are broken by sorting on
+    /// `(group, version)` so the result is deterministic rather than
+    /// depending on hash map iteration order. Use `resolve_gvk` if you need
+    /// an exact, unambiguous group/version instead.
+    fn resolve(&self, kind: &str) -> Option<&ApiResource> {
+        let mut candidates: Vec<&ApiResource> = self.resources.values().filter(|r| r.kind == kind).collect();
+        candidates.sort_by(|a, b| (&a.group, &a.version).cmp(&(&b.group, &b.version)));
+        candidates
+            .iter()
+            .find(|r| self.is_preferred_version(r))
+            .or_else(|| candidates.first())
+            .copied()
+    }
+
+    fn is_preferred_version(&self, resource: &ApiResource) -> bool {
+        self.preferred_versions
+            .get(&resource.group)
+            .map_or(false, |v| v == &resource.version)
+    }
+
+    /// Resolve a resource by its exact group, version and kind
+    fn resolve_gvk(&self, group: &str, version: &str, kind: &str) -> Option<&ApiResource> {
+        self.resources.get(&GroupVersionKind {
+            group: group.to_string(),
+            version: version.to_string(),
+            kind: kind.to_string(),
+        })
+    }
+}
+
+/// Cluster API discovery
+///
+/// Queries the live cluster's `/api` and `/apis` endpoints to build a map of
+/// every installed resource, keyed by its `GroupVersionKind`. This lets callers
+/// construct a working `RawApi`/`Api` for a CRD without hardcoding its plural.
+pub struct Discovery {
+    client: APIClient,
+    index: ResourceIndex,
+}
+
+impl Discovery {
+    /// Create a `Discovery` for the given client with nothing fetched yet
+    pub fn new(client: APIClient) -> Self {
+        Discovery {
+            client,
+            index: ResourceIndex::default(),
+        }
+    }
+
+    /// Query the cluster and (re)populate the resource map
+    ///
+    /// Fetches the core `/api/v1` resource list, then walks `/apis` for every
+    /// installed group and version, recording each group's `preferredVersion`
+    /// so `resolve` can pick deterministically when a kind spans versions.
+    pub async fn run(&mut self) -> Result<()> {
+        self.index = ResourceIndex::default();
+
+        let core: APIResourceList = self.client.request(Self::get_request("/api/v1")?).await?;
+        self.index.set_preferred_version("", "v1");
+        self.index.index_resource_list("", "v1", core);
+
+        let groups: APIGroupList = self.client.request(Self::get_request("/apis")?).await?;
+        for group in groups.groups {
+            if let Some(pv) = &group.preferred_version {
+                self.index.set_preferred_version(&group.name, &pv.version);
+            }
+            for gv in &group.versions {
+                let path = format!("/apis/{}/{}", group.name, gv.version);
+                let list: APIResourceList = self.client.request(Self::get_request(&path)?).await?;
+                self.index.index_resource_list(&group.name, &gv.version, list);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_request(path: &str) -> Result<http::Request<Vec<u8>>> {
+        http::Request::get(path).body(vec![]).map_err(Error::from)
+    }
+
+    /// Resolve a `kind` to its `ApiResource`, assuming it is unambiguous across groups
+    ///
+    /// If the same kind is installed under multiple groups, use `resolve_gvk` instead.
+    pub fn resolve(&self, kind: &str) -> Option<&ApiResource> {
+        self.index.resolve(kind)
+    }
+
+    /// Resolve a resource by its exact group, version and kind
+    pub fn resolve_gvk(&self, group: &str, version: &str, kind: &str) -> Option<&ApiResource> {
+        self.index.resolve_gvk(group, version, kind)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{APIResourceInfo, APIResourceList, ResourceIndex};
+
+    fn resource(name: &str, kind: &str, namespaced: bool) -> APIResourceInfo {
+        APIResourceInfo {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            namespaced,
+            verbs: vec!["get".to_string(), "list".to_string()],
+        }
+    }
+
+    #[test]
+    fn index_resource_list_skips_subresources() {
+        let mut idx = ResourceIndex::default();
+        idx.index_resource_list(
+            "apps",
+            "v1",
+            APIResourceList {
+                resources: vec![resource("deployments", "Deployment", true), resource(
+                    "deployments/status",
+                    "Deployment",
+                    true,
+                )],
+            },
+        );
+        assert!(idx.resolve_gvk("apps", "v1", "Deployment").is_some());
+        assert_eq!(idx.resources.len(), 1);
+    }
+
+    #[test]
+    fn resolve_finds_kind_across_groups() {
+        let mut idx = ResourceIndex::default();
+        idx.index_resource_list(
+            "clux.dev",
+            "v1",
+            APIResourceList {
+                resources: vec![resource("foos", "Foo", true)],
+            },
+        );
+        assert_eq!(idx.resolve("Foo").unwrap().name, "foos");
+        assert!(idx.resolve("Bar").is_none());
+    }
+
+    #[test]
+    fn resolve_gvk_requires_exact_match() {
+        let mut idx = ResourceIndex::default();
+        idx.index_resource_list(
+            "clux.dev",
+            "v1",
+            APIResourceList {
+                resources: vec![resource("foos", "Foo", true)],
+            },
+        );
+        assert!(idx.resolve_gvk("clux.dev", "v1", "Foo").is_some());
+        assert!(idx.resolve_gvk("clux.dev", "v1beta1", "Foo").is_none());
+        assert!(idx.resolve_gvk("other.dev", "v1", "Foo").is_none());
+    }
+
+    #[test]
+    fn resolve_prefers_group_preferred_version_deterministically() {
+        let mut idx = ResourceIndex::default();
+        // same kind installed at two versions of the same group - v1beta1 indexed first
+        idx.index_resource_list(
+            "clux.dev",
+            "v1beta1",
+            APIResourceList {
+                resources: vec![resource("foos", "Foo", true)],
+            },
+        );
+        idx.index_resource_list(
+            "clux.dev",
+            "v1",
+            APIResourceList {
+                resources: vec![resource("foos", "Foo", true)],
+            },
+        );
+        idx.set_preferred_version("clux.dev", "v1");
+        assert_eq!(idx.resolve("Foo").unwrap().version, "v1");
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The common Kubernetes object metadata fields carried by every resource
+///
+/// Fields the apiserver sends that aren't named here (`uid`,
+/// `creationTimestamp`, `ownerReferences`, `finalizers`, `generation`, ...)
+/// are kept in `extra` rather than dropped, so a get -> mutate -> replace
+/// round trip doesn't strip them from the live object.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ObjectMeta {
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    #[serde(rename = "resourceVersion")]
+    pub resource_version: Option<String>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// A type-erased Kubernetes object for resources discovered at runtime
+///
+/// Carries just enough typed metadata (`apiVersion`, `kind`, `metadata`) to
+/// satisfy the API machinery, with the rest of the object - spec, status, or
+/// any custom fields - kept as raw JSON. Lets a `CustomResource` be used
+/// without generating a Rust struct for it first.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DynamicObject {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    #[serde(flatten)]
+    pub data: Value,
+}
+
+#[cfg(test)]
+mod test {
+    use super::DynamicObject;
+    use serde_json::json;
+
+    #[test]
+    fn dynamic_object_round_trips_arbitrary_fields() {
+        let raw = json!({
+            "apiVersion": "clux.dev/v1",
+            "kind": "Foo",
+            "metadata": {"name": "baz", "namespace": "myns"},
+            "spec": {"replicas": 3},
+        });
+        let obj: DynamicObject = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(obj.api_version, "clux.dev/v1");
+        assert_eq!(obj.kind, "Foo");
+        assert_eq!(obj.metadata.name.as_deref(), Some("baz"));
+        assert_eq!(obj.data["spec"]["replicas"], 3);
+
+        let rendered = serde_json::to_value(&obj).unwrap();
+        assert_eq!(rendered["spec"]["replicas"], 3);
+    }
+
+    #[test]
+    fn dynamic_object_round_trips_unmodeled_metadata() {
+        let raw = json!({
+            "apiVersion": "clux.dev/v1",
+            "kind": "Foo",
+            "metadata": {
+                "name": "baz",
+                "uid": "abc-123",
+                "creationTimestamp": "2020-01-01T00:00:00Z",
+                "generation": 2,
+                "ownerReferences": [{"kind": "Bar", "name": "owner"}],
+            },
+            "spec": {},
+        });
+        let obj: DynamicObject = serde_json::from_value(raw.clone()).unwrap();
+        let rendered = serde_json::to_value(&obj).unwrap();
+        assert_eq!(rendered["metadata"]["uid"], "abc-123");
+        assert_eq!(rendered["metadata"]["generation"], 2);
+        assert_eq!(rendered["metadata"]["ownerReferences"][0]["name"], "owner");
+    }
+}
@@ -0,0 +1,11 @@
+mod crds;
+mod discovery;
+mod dynamic;
+mod raw;
+mod typed;
+
+pub use crds::{CrBuilder, CustomResource};
+pub use discovery::{ApiResource, Discovery, GroupVersionKind};
+pub use dynamic::{DynamicObject, ObjectMeta};
+pub use raw::{PatchParams, PostParams, RawApi};
+pub use typed::Api;
@@ -0,0 +1,162 @@
+use crate::{Error, Result};
+use http::Request;
+use inflector::string::pluralize::to_plural;
+use std::marker::PhantomData;
+
+/// Parameters for list/watch calls
+#[derive(Default, Clone)]
+pub struct ListParams {
+    pub label_selector: Option<String>,
+    pub field_selector: Option<String>,
+}
+
+/// Parameters for post/put calls
+#[derive(Default, Clone)]
+pub struct PostParams {
+    pub dry_run: bool,
+}
+
+/// Parameters for delete calls
+#[derive(Default, Clone)]
+pub struct DeleteParams {
+    pub dry_run: bool,
+}
+
+/// Parameters for patch calls
+#[derive(Default, Clone)]
+pub struct PatchParams {
+    pub dry_run: bool,
+    pub force: bool,
+}
+
+/// A generic Kubernetes resource API, parameterised by the response type `K`
+///
+/// Normally built from a `Resource` impl on `K`, but can also be built
+/// directly from a `CustomResource` to work against CRDs without requiring
+/// `K` to carry any Kubernetes metadata of its own.
+pub struct RawApi<K> {
+    pub(crate) api_version: String,
+    pub(crate) kind: String,
+    pub(crate) group: String,
+    pub(crate) version: String,
+    pub(crate) namespace: Option<String>,
+    /// Authoritative plural, overriding the `to_plural(kind)` guess when set
+    pub(crate) resource: Option<String>,
+    pub(crate) phantom: PhantomData<K>,
+}
+
+impl<K> RawApi<K> {
+    /// The plural resource name used in request URIs
+    fn plural(&self) -> String {
+        self.resource
+            .clone()
+            .unwrap_or_else(|| to_plural(&self.kind.to_lowercase()))
+    }
+
+    fn base_url(&self) -> String {
+        if self.group.is_empty() {
+            format!("/api/{}", self.version)
+        } else {
+            format!("/apis/{}/{}", self.group, self.version)
+        }
+    }
+
+    fn resource_url(&self) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{}/namespaces/{}/{}", self.base_url(), ns, self.plural()),
+            None => format!("{}/{}", self.base_url(), self.plural()),
+        }
+    }
+
+    fn dry_run_qp(dry_run: bool) -> String {
+        if dry_run {
+            "dryRun=All".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Create a request to create a new instance of the resource
+    pub fn create(&self, pp: &PostParams, data: Vec<u8>) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!("{}?{}", self.resource_url(), Self::dry_run_qp(pp.dry_run));
+        Request::post(urlstr).body(data).map_err(Error::from)
+    }
+
+    /// Create a request to fetch a single named instance of the resource
+    pub fn get(&self, name: &str) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!("{}/{}", self.resource_url(), name);
+        Request::get(urlstr).body(vec![]).map_err(Error::from)
+    }
+
+    /// Create a request to list instances of the resource
+    pub fn list(&self, _lp: &ListParams) -> Result<Request<Vec<u8>>> {
+        Request::get(self.resource_url()).body(vec![]).map_err(Error::from)
+    }
+
+    /// Create a request to delete a single named instance of the resource
+    pub fn delete(&self, name: &str, pp: &DeleteParams) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!(
+            "{}/{}?{}",
+            self.resource_url(),
+            name,
+            Self::dry_run_qp(pp.dry_run)
+        );
+        Request::delete(urlstr).body(vec![]).map_err(Error::from)
+    }
+
+    /// Create a request to replace a single named instance of the resource
+    pub fn replace(&self, name: &str, pp: &PostParams, data: Vec<u8>) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!(
+            "{}/{}?{}",
+            self.resource_url(),
+            name,
+            Self::dry_run_qp(pp.dry_run)
+        );
+        Request::put(urlstr).body(data).map_err(Error::from)
+    }
+
+    /// Create a request to patch a single named instance of the resource
+    pub fn patch(&self, name: &str, pp: &PatchParams, patch: Vec<u8>) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!(
+            "{}/{}?{}",
+            self.resource_url(),
+            name,
+            Self::dry_run_qp(pp.dry_run)
+        );
+        Request::patch(urlstr)
+            .header("Content-Type", "application/merge-patch+json")
+            .body(patch)
+            .map_err(Error::from)
+    }
+
+    /// Create a request to fetch the `/status` subresource of a named instance
+    pub fn get_status(&self, name: &str) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!("{}/{}/status", self.resource_url(), name);
+        Request::get(urlstr).body(vec![]).map_err(Error::from)
+    }
+
+    /// Create a request to replace the `/status` subresource of a named instance
+    pub fn replace_status(&self, name: &str, pp: &PostParams, data: Vec<u8>) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!(
+            "{}/{}/status?{}",
+            self.resource_url(),
+            name,
+            Self::dry_run_qp(pp.dry_run)
+        );
+        Request::put(urlstr).body(data).map_err(Error::from)
+    }
+
+    /// Create a request to patch the `/status` subresource of a named instance
+    pub fn patch_status(&self, name: &str, pp: &PatchParams, patch: Vec<u8>) -> Result<Request<Vec<u8>>> {
+        let urlstr = format!(
+            "{}/{}/status?{}",
+            self.resource_url(),
+            name,
+            Self::dry_run_qp(pp.dry_run)
+        );
+        Request::patch(urlstr)
+            .header("Content-Type", "application/merge-patch+json")
+            .body(patch)
+            .map_err(Error::from)
+    }
+}